@@ -5,6 +5,9 @@
 pub enum HarnessType {
     ByteSize = 0,
     String = 1,
+    /// Drive an external instrumented binary over the AFL forkserver protocol
+    /// instead of calling `target_fn` in-process. See `target_path`/`target_argv`.
+    Forkserver = 2,
 }
 
 #[repr(C)]
@@ -12,6 +15,28 @@ pub enum HarnessType {
 pub enum SchedulerType {
     Queue = 0,
     Weighted = 1,
+    /// AFL-style power scheduling: testcases are calibrated for exec time and
+    /// bitmap density, then get an energy budget from `power_schedule`.
+    /// On the single-core in-process path with CmpLog enabled, the CmpLog
+    /// stages run instead and no calibration or power mutational stage is
+    /// used. Forkserver targets use the plain queue scheduler instead.
+    PowerQueue = 2,
+    /// Wraps the queue scheduler in an `IndexesLenTimeMinimizerScheduler`,
+    /// favoring the smallest, fastest input that covers each known edge.
+    Minimizer = 3,
+}
+
+/// Selects how `SchedulerType::PowerQueue` weighs exec-time ratio,
+/// bitmap-size ratio, and discovery depth into an energy/mutation budget.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerSchedule {
+    Explore = 0,
+    Fast = 1,
+    Coe = 2,
+    Lin = 3,
+    Quad = 4,
+    Exploit = 5,
 }
 
 #[repr(C)]
@@ -27,6 +52,23 @@ pub struct PeelFuzzConfig {
     pub seed_count: u32,
     /// Number of cores for parallel fuzzing. 0 = auto-detect (all available cores).
     pub core_count: u32,
+    /// Enable CmpLog input-to-state mutation. Requires the `cmplog` feature.
+    pub use_cmplog: bool,
+    /// Path to an AFL-style dictionary file. Null = no dictionary.
+    pub dict_path: *const i8,
+    /// Path to the instrumented target binary. Only used for `HarnessType::Forkserver`.
+    pub target_path: *const i8,
+    /// Null-terminated `argv` for the target binary, AFL-style. An argument
+    /// equal to `"@@"` is replaced with the path of the current input file.
+    /// Null = the input is delivered on the target's stdin instead.
+    pub target_argv: *const *const i8,
+    /// Shared-memory coverage map size in bytes for forkserver mode. 0 = default (65536).
+    pub shmem_size: u32,
+    /// Power schedule used when `scheduler_type` is `SchedulerType::PowerQueue`.
+    pub power_schedule: PowerSchedule,
+    /// Deduplicate crashes by backtrace hash before saving to `crash_dir`,
+    /// instead of saving every crashing input.
+    pub dedup_crashes: bool,
 }
 
 impl PeelFuzzConfig {
@@ -67,4 +109,61 @@ impl PeelFuzzConfig {
             }
         }
     }
+
+    /// Returns the dictionary path, or `None` if `dict_path` is null.
+    pub fn dict_path_opt(&self) -> Option<String> {
+        if self.dict_path.is_null() {
+            None
+        } else {
+            unsafe {
+                Some(
+                    core::ffi::CStr::from_ptr(self.dict_path)
+                        .to_string_lossy()
+                        .into_owned(),
+                )
+            }
+        }
+    }
+
+    /// Returns the forkserver target path, or `None` if `target_path` is null.
+    pub fn target_path_opt(&self) -> Option<String> {
+        if self.target_path.is_null() {
+            None
+        } else {
+            unsafe {
+                Some(
+                    core::ffi::CStr::from_ptr(self.target_path)
+                        .to_string_lossy()
+                        .into_owned(),
+                )
+            }
+        }
+    }
+
+    /// Collects `target_argv` into an owned `Vec<String>`, stopping at the
+    /// null terminator. Returns an empty vec if `target_argv` is null.
+    pub fn target_argv_vec(&self) -> Vec<String> {
+        if self.target_argv.is_null() {
+            return Vec::new();
+        }
+
+        let mut args = Vec::new();
+        unsafe {
+            let mut cursor = self.target_argv;
+            while !(*cursor).is_null() {
+                args.push(core::ffi::CStr::from_ptr(*cursor).to_string_lossy().into_owned());
+                cursor = cursor.add(1);
+            }
+        }
+        args
+    }
+
+    /// Returns the configured shared-memory map size, or the default (65536).
+    pub fn shmem_size_or_default(&self) -> usize {
+        if self.shmem_size == 0 {
+            65536
+        } else {
+            self.shmem_size as usize
+        }
+    }
 }
@@ -0,0 +1,179 @@
+//! AFL-style dictionary parsing for token/keyword mutations.
+//!
+//! Parses files of the form used by AFL++ `-x` dictionaries: one entry per
+//! line, either `name="value"` or a bare `"value"`, where `value` may embed
+//! `\xNN` hex escapes. Blank lines, `#`-comments, and an optional trailing
+//! `@level` precedence suffix are ignored.
+
+use std::fs;
+use std::path::Path;
+
+/// Parse a single AFL dictionary file into raw token byte strings.
+pub fn parse_dict_file(path: &Path) -> std::io::Result<Vec<Vec<u8>>> {
+    let contents = fs::read_to_string(path)?;
+    let mut tokens = Vec::new();
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        // Locate the quoted value directly instead of splitting on `=`/`@`,
+        // since either byte can legitimately appear inside the value itself
+        // (e.g. `mail="user@example.com"`, `"a=b"`). Anything before the
+        // opening quote is the optional `name=` prefix; anything after the
+        // closing quote is the optional `@level` suffix, and both are
+        // discarded regardless of their contents.
+        let Some(quoted) = find_quoted(line) else {
+            continue;
+        };
+
+        if let Some(token) = parse_quoted(quoted) {
+            tokens.push(token);
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Parse multiple dictionary files, concatenating and de-duplicating tokens.
+pub fn parse_dict_files<I, P>(paths: I) -> std::io::Result<Vec<Vec<u8>>>
+where
+    I: IntoIterator<Item = P>,
+    P: AsRef<Path>,
+{
+    let mut tokens = Vec::new();
+    for path in paths {
+        tokens.extend(parse_dict_file(path.as_ref())?);
+    }
+    tokens.sort();
+    tokens.dedup();
+    Ok(tokens)
+}
+
+/// Find the first double-quoted span in `line`, honoring `\"` escapes so a
+/// quote embedded in the value doesn't end the span early. Returns the span
+/// including its surrounding quotes.
+fn find_quoted(line: &str) -> Option<&str> {
+    let bytes = line.as_bytes();
+    let start = line.find('"')?;
+    let mut i = start + 1;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if i + 1 < bytes.len() => i += 2,
+            b'"' => return Some(&line[start..=i]),
+            _ => i += 1,
+        }
+    }
+
+    None
+}
+
+/// Decode a double-quoted AFL dictionary value, unescaping `\xNN` and `\\`/`\"`.
+fn parse_quoted(s: &str) -> Option<Vec<u8>> {
+    let inner = s.strip_prefix('"')?.strip_suffix('"')?;
+    let mut out = Vec::with_capacity(inner.len());
+    let bytes = inner.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'\\' && i + 1 < bytes.len() {
+            match bytes[i + 1] {
+                b'x' if i + 3 < bytes.len() => {
+                    let hex = core::str::from_utf8(&bytes[i + 2..i + 4]).ok()?;
+                    out.push(u8::from_str_radix(hex, 16).ok()?);
+                    i += 4;
+                }
+                b'\\' => {
+                    out.push(b'\\');
+                    i += 2;
+                }
+                b'"' => {
+                    out.push(b'"');
+                    i += 2;
+                }
+                _ => {
+                    out.push(bytes[i]);
+                    i += 1;
+                }
+            }
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_quoted_honors_escaped_quotes() {
+        assert_eq!(find_quoted(r#"kw="a\"b"@3"#), Some(r#""a\"b""#));
+    }
+
+    #[test]
+    fn find_quoted_returns_none_without_a_quote() {
+        assert_eq!(find_quoted("not a dictionary line"), None);
+    }
+
+    #[test]
+    fn parse_quoted_decodes_hex_escapes() {
+        assert_eq!(parse_quoted(r#""\x41\x42""#), Some(vec![0x41, 0x42]));
+    }
+
+    #[test]
+    fn parse_quoted_keeps_truncated_hex_escape_literal() {
+        assert_eq!(parse_quoted(r#""\x4""#), Some(vec![b'\\', b'x', b'4']));
+    }
+
+    #[test]
+    fn parse_quoted_unescapes_backslash_and_quote() {
+        assert_eq!(parse_quoted(r#""a\\b\"c""#), Some(b"a\\b\"c".to_vec()));
+    }
+
+    #[test]
+    fn parse_quoted_empty_token() {
+        assert_eq!(parse_quoted("\"\""), Some(Vec::new()));
+    }
+
+    #[test]
+    fn parse_dict_file_strips_name_prefix_and_level_suffix() {
+        let path = std::env::temp_dir().join(format!("peelfuzz_dict_test_{}.dict", std::process::id()));
+        fs::write(
+            &path,
+            "kw=\"AB\"@3\n\"bare\"\nmail=\"user@example.com\"\n# comment\n\n",
+        )
+        .unwrap();
+
+        let tokens = parse_dict_file(&path).unwrap();
+        fs::remove_file(&path).ok();
+
+        assert_eq!(
+            tokens,
+            vec![b"AB".to_vec(), b"bare".to_vec(), b"user@example.com".to_vec()]
+        );
+    }
+
+    #[test]
+    fn parse_dict_files_dedups_across_files() {
+        let path_a = std::env::temp_dir().join(format!("peelfuzz_dict_test_a_{}.dict", std::process::id()));
+        let path_b = std::env::temp_dir().join(format!("peelfuzz_dict_test_b_{}.dict", std::process::id()));
+        fs::write(&path_a, "\"dup\"\n\"only_a\"\n").unwrap();
+        fs::write(&path_b, "\"dup\"\n\"only_b\"\n").unwrap();
+
+        let tokens = parse_dict_files([&path_a, &path_b]).unwrap();
+        fs::remove_file(&path_a).ok();
+        fs::remove_file(&path_b).ok();
+
+        assert_eq!(
+            tokens,
+            vec![b"dup".to_vec(), b"only_a".to_vec(), b"only_b".to_vec()]
+        );
+    }
+}
@@ -4,7 +4,7 @@ use std::time::Duration;
 use libafl::executors::ExitKind;
 use libafl::inputs::BytesInput;
 
-use crate::config::SchedulerType;
+use crate::config::{PowerSchedule, SchedulerType};
 
 /// Builder for configuring and running a PeelFuzz fuzzing session.
 ///
@@ -32,6 +32,10 @@ where
     seed_count: usize,
     core_count: usize,
     tui: bool,
+    cmplog: bool,
+    dict_paths: Vec<String>,
+    power_schedule: PowerSchedule,
+    dedup_crashes: bool,
 }
 
 impl<H> PeelFuzzer<H>
@@ -48,6 +52,10 @@ where
             seed_count: 8,
             core_count: 1,
             tui: false,
+            cmplog: false,
+            dict_paths: Vec::new(),
+            power_schedule: PowerSchedule::Explore,
+            dedup_crashes: false,
         }
     }
 
@@ -87,6 +95,47 @@ where
         self
     }
 
+    /// Enable CmpLog input-to-state mutation (requires the `cmplog` feature).
+    ///
+    /// When the `cmplog` feature isn't compiled in, this is a no-op — the
+    /// fuzzer falls back to plain havoc mutation.
+    pub fn cmplog(mut self, enabled: bool) -> Self {
+        self.cmplog = enabled;
+        self
+    }
+
+    /// Load an AFL-style dictionary file for token/keyword mutations. Pass
+    /// `None` for no dictionary; it does not clear paths added by a prior
+    /// `dict_path`/`tokens_file` call. Each call adds one more file; all added
+    /// files are merged into a single token set.
+    pub fn dict_path(mut self, path: Option<&str>) -> Self {
+        if let Some(path) = path {
+            self.dict_paths.push(path.to_string());
+        }
+        self
+    }
+
+    /// Load an additional AFL-style dictionary file for token/keyword
+    /// mutations; same as `dict_path(Some(path))`. Call it once per file to
+    /// fuzz with several dictionaries merged together.
+    pub fn tokens_file(mut self, path: &str) -> Self {
+        self.dict_paths.push(path.to_string());
+        self
+    }
+
+    /// Select the energy formula used by `SchedulerType::PowerQueue`.
+    pub fn power_schedule(mut self, schedule: PowerSchedule) -> Self {
+        self.power_schedule = schedule;
+        self
+    }
+
+    /// Deduplicate crashes by backtrace hash: a crashing input is only saved
+    /// to `crash_dir` when its call stack hasn't been seen before.
+    pub fn dedup_crashes(mut self, enabled: bool) -> Self {
+        self.dedup_crashes = enabled;
+        self
+    }
+
     /// Run the fuzzer. This consumes the builder and starts the fuzz loop.
     ///
     /// # Safety
@@ -101,6 +150,10 @@ where
             seed_count,
             core_count,
             tui,
+            cmplog,
+            dict_paths,
+            power_schedule,
+            dedup_crashes,
         } = self;
 
         // Choose multicore vs single-core path
@@ -110,23 +163,41 @@ where
                 match (scheduler_type, tui) {
                     (SchedulerType::Queue, false) => {
                         let mon = crate::monitors::multi_monitor();
-                        run_engine_multicore!(harness, mon, crash_dir, seed_count, timeout, core_count, |_s, _o| {
+                        run_engine_multicore!(harness, mon, crash_dir, seed_count, timeout, core_count, cmplog, &dict_paths, dedup_crashes, None, |_s, _o| {
                             libafl::schedulers::QueueScheduler::new()
                         });
                     }
                     (SchedulerType::Weighted, false) => {
                         let mon = crate::monitors::multi_monitor();
                         run_engine_multicore!(
-                            harness, mon, crash_dir, seed_count, timeout, core_count,
+                            harness, mon, crash_dir, seed_count, timeout, core_count, cmplog, &dict_paths, dedup_crashes, None,
                             |state, observer| crate::schedulers::StdWeightedScheduler::new(
                                 &mut state, &observer
                             )
                         );
                     }
+                    (SchedulerType::PowerQueue, false) => {
+                        let mon = crate::monitors::multi_monitor();
+                        run_engine_multicore!(
+                            harness, mon, crash_dir, seed_count, timeout, core_count, cmplog, &dict_paths, dedup_crashes, Some(power_schedule),
+                            |state, observer| libafl::schedulers::powersched::PowerQueueScheduler::new(
+                                &mut state, &observer, libafl_power_schedule(power_schedule)
+                            )
+                        );
+                    }
+                    (SchedulerType::Minimizer, false) => {
+                        let mon = crate::monitors::multi_monitor();
+                        run_engine_multicore!(
+                            harness, mon, crash_dir, seed_count, timeout, core_count, cmplog, &dict_paths, dedup_crashes, None,
+                            |_state, observer| libafl::schedulers::IndexesLenTimeMinimizerScheduler::new(
+                                &observer, libafl::schedulers::QueueScheduler::new()
+                            )
+                        );
+                    }
                     #[cfg(feature = "tui")]
                     (SchedulerType::Queue, true) => {
                         let mon = crate::monitors::tui_monitor();
-                        run_engine_multicore!(harness, mon, crash_dir, seed_count, timeout, core_count, |_s, _o| {
+                        run_engine_multicore!(harness, mon, crash_dir, seed_count, timeout, core_count, cmplog, &dict_paths, dedup_crashes, None, |_s, _o| {
                             libafl::schedulers::QueueScheduler::new()
                         });
                     }
@@ -134,30 +205,66 @@ where
                     (SchedulerType::Weighted, true) => {
                         let mon = crate::monitors::tui_monitor();
                         run_engine_multicore!(
-                            harness, mon, crash_dir, seed_count, timeout, core_count,
+                            harness, mon, crash_dir, seed_count, timeout, core_count, cmplog, &dict_paths, dedup_crashes, None,
                             |state, observer| crate::schedulers::StdWeightedScheduler::new(
                                 &mut state, &observer
                             )
                         );
                     }
+                    #[cfg(feature = "tui")]
+                    (SchedulerType::PowerQueue, true) => {
+                        let mon = crate::monitors::tui_monitor();
+                        run_engine_multicore!(
+                            harness, mon, crash_dir, seed_count, timeout, core_count, cmplog, &dict_paths, dedup_crashes, Some(power_schedule),
+                            |state, observer| libafl::schedulers::powersched::PowerQueueScheduler::new(
+                                &mut state, &observer, libafl_power_schedule(power_schedule)
+                            )
+                        );
+                    }
+                    #[cfg(feature = "tui")]
+                    (SchedulerType::Minimizer, true) => {
+                        let mon = crate::monitors::tui_monitor();
+                        run_engine_multicore!(
+                            harness, mon, crash_dir, seed_count, timeout, core_count, cmplog, &dict_paths, dedup_crashes, None,
+                            |_state, observer| libafl::schedulers::IndexesLenTimeMinimizerScheduler::new(
+                                &observer, libafl::schedulers::QueueScheduler::new()
+                            )
+                        );
+                    }
                     #[cfg(not(feature = "tui"))]
                     (_, true) => {
                         eprintln!("TUI requested but `tui` feature not compiled. Falling back to console.");
                         let mon = crate::monitors::multi_monitor();
                         match scheduler_type {
                             SchedulerType::Queue => {
-                                run_engine_multicore!(harness, mon, crash_dir, seed_count, timeout, core_count, |_s, _o| {
+                                run_engine_multicore!(harness, mon, crash_dir, seed_count, timeout, core_count, cmplog, &dict_paths, dedup_crashes, None, |_s, _o| {
                                     libafl::schedulers::QueueScheduler::new()
                                 });
                             }
                             SchedulerType::Weighted => {
                                 run_engine_multicore!(
-                                    harness, mon, crash_dir, seed_count, timeout, core_count,
+                                    harness, mon, crash_dir, seed_count, timeout, core_count, cmplog, &dict_paths, dedup_crashes, None,
                                     |state, observer| crate::schedulers::StdWeightedScheduler::new(
                                         &mut state, &observer
                                     )
                                 );
                             }
+                            SchedulerType::PowerQueue => {
+                                run_engine_multicore!(
+                                    harness, mon, crash_dir, seed_count, timeout, core_count, cmplog, &dict_paths, dedup_crashes, Some(power_schedule),
+                                    |state, observer| libafl::schedulers::powersched::PowerQueueScheduler::new(
+                                        &mut state, &observer, libafl_power_schedule(power_schedule)
+                                    )
+                                );
+                            }
+                            SchedulerType::Minimizer => {
+                                run_engine_multicore!(
+                                    harness, mon, crash_dir, seed_count, timeout, core_count, cmplog, &dict_paths, dedup_crashes, None,
+                                    |_state, observer| libafl::schedulers::IndexesLenTimeMinimizerScheduler::new(
+                                        &observer, libafl::schedulers::QueueScheduler::new()
+                                    )
+                                );
+                            }
                         }
                     }
                 }
@@ -166,11 +273,114 @@ where
             {
                 eprintln!("Multi-core requested but `fork` feature not compiled. Falling back to single-core.");
                 // Fall through to single-core below
-                unsafe { single_core_run(&mut harness, scheduler_type, timeout, crash_dir, seed_count, tui) };
+                unsafe { single_core_run(&mut harness, scheduler_type, timeout, crash_dir, seed_count, tui, cmplog, &dict_paths, dedup_crashes, power_schedule) };
                 return;
             }
         } else {
-            unsafe { single_core_run(&mut harness, scheduler_type, timeout, crash_dir, seed_count, tui) };
+            unsafe { single_core_run(&mut harness, scheduler_type, timeout, crash_dir, seed_count, tui, cmplog, &dict_paths, dedup_crashes, power_schedule) };
+        }
+    }
+}
+
+/// `forkserver` is an alternate constructor for targets that have no Rust
+/// harness closure at all, so it's implemented on a concrete instantiation
+/// of `PeelFuzzer` rather than the builder's own generic `impl<H>` block.
+impl PeelFuzzer<fn(&BytesInput) -> ExitKind> {
+    /// Start building a fuzzer that drives an external, already-instrumented
+    /// binary over the AFL forkserver protocol instead of calling an
+    /// in-process harness. `target_argv` is the target's AFL-style argv,
+    /// where an argument equal to `"@@"` is replaced with the current input's
+    /// file path; pass an empty vec to deliver inputs on stdin instead.
+    ///
+    /// See [`ForkserverFuzzer`].
+    pub fn forkserver(target_path: &str, target_argv: Vec<String>) -> ForkserverFuzzer {
+        ForkserverFuzzer::new(target_path, target_argv)
+    }
+}
+
+/// Builder for fuzzing an external instrumented binary over the AFL
+/// forkserver protocol, instead of an in-process Rust harness. Construct one
+/// with [`PeelFuzzer::forkserver`].
+///
+/// # Example
+/// ```rust,no_run
+/// unsafe {
+///     PeelFuzzer::forkserver("./target", vec!["@@".to_string()])
+///         .scheduler(SchedulerType::Weighted)
+///         .timeout(Duration::from_secs(2))
+///         .run();
+/// }
+/// ```
+pub struct ForkserverFuzzer {
+    target_path: String,
+    target_argv: Vec<String>,
+    scheduler_type: SchedulerType,
+    timeout: Duration,
+    crash_dir: String,
+    seed_count: usize,
+    shmem_size: usize,
+}
+
+impl ForkserverFuzzer {
+    fn new(target_path: &str, target_argv: Vec<String>) -> Self {
+        Self {
+            target_path: target_path.to_string(),
+            target_argv,
+            scheduler_type: SchedulerType::Queue,
+            timeout: Duration::from_secs(1),
+            crash_dir: "./crashes".to_string(),
+            seed_count: 8,
+            shmem_size: 65536,
+        }
+    }
+
+    /// Select the scheduler strategy.
+    pub fn scheduler(mut self, scheduler_type: SchedulerType) -> Self {
+        self.scheduler_type = scheduler_type;
+        self
+    }
+
+    /// Set the executor timeout per input.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the directory for crash outputs.
+    pub fn crash_dir(mut self, dir: &str) -> Self {
+        self.crash_dir = dir.to_string();
+        self
+    }
+
+    /// Set the number of initial seed inputs.
+    pub fn seed_count(mut self, count: usize) -> Self {
+        self.seed_count = count;
+        self
+    }
+
+    /// Set the shared-memory coverage map size in bytes. Must match the
+    /// `AFL_MAP_SIZE` the target binary was compiled with.
+    pub fn shmem_size(mut self, size: usize) -> Self {
+        self.shmem_size = size;
+        self
+    }
+
+    /// Run the fuzzer. This consumes the builder and starts the fuzz loop.
+    ///
+    /// # Safety
+    /// `target_path` must point at a binary built with AFL/LibAFL coverage
+    /// instrumentation matching `shmem_size`.
+    pub unsafe fn run(self) {
+        unsafe {
+            run_forkserver(
+                &self.target_path,
+                self.target_argv,
+                self.scheduler_type,
+                self.timeout,
+                self.crash_dir,
+                self.seed_count,
+                self.shmem_size,
+            );
         }
     }
 }
@@ -182,13 +392,17 @@ unsafe fn single_core_run<H>(
     crash_dir: String,
     seed_count: usize,
     tui: bool,
+    cmplog: bool,
+    dict_paths: &[String],
+    dedup_crashes: bool,
+    power_schedule: PowerSchedule,
 ) where
     H: FnMut(&BytesInput) -> ExitKind,
 {
     match (scheduler_type, tui) {
         (SchedulerType::Queue, false) => {
             let mon = crate::monitors::simple_monitor();
-            run_engine!(harness, mon, crash_dir, seed_count, timeout, |_s, _o| {
+            run_engine!(harness, mon, crash_dir, seed_count, timeout, cmplog, dict_paths, dedup_crashes, None, |_s, _o| {
                 libafl::schedulers::QueueScheduler::new()
             });
         }
@@ -200,15 +414,53 @@ unsafe fn single_core_run<H>(
                 crash_dir,
                 seed_count,
                 timeout,
+                cmplog,
+                dict_paths,
+                dedup_crashes,
+                None,
                 |state, observer| crate::schedulers::StdWeightedScheduler::new(
                     &mut state, &observer
                 )
             );
         }
+        (SchedulerType::PowerQueue, false) => {
+            let mon = crate::monitors::simple_monitor();
+            run_engine!(
+                harness,
+                mon,
+                crash_dir,
+                seed_count,
+                timeout,
+                cmplog,
+                dict_paths,
+                dedup_crashes,
+                Some(power_schedule),
+                |state, observer| libafl::schedulers::powersched::PowerQueueScheduler::new(
+                    &mut state, &observer, libafl_power_schedule(power_schedule)
+                )
+            );
+        }
+        (SchedulerType::Minimizer, false) => {
+            let mon = crate::monitors::simple_monitor();
+            run_engine!(
+                harness,
+                mon,
+                crash_dir,
+                seed_count,
+                timeout,
+                cmplog,
+                dict_paths,
+                dedup_crashes,
+                None,
+                |_state, observer| libafl::schedulers::IndexesLenTimeMinimizerScheduler::new(
+                    &observer, libafl::schedulers::QueueScheduler::new()
+                )
+            );
+        }
         #[cfg(feature = "tui")]
         (SchedulerType::Queue, true) => {
             let mon = crate::monitors::tui_monitor();
-            run_engine!(harness, mon, crash_dir, seed_count, timeout, |_s, _o| {
+            run_engine!(harness, mon, crash_dir, seed_count, timeout, cmplog, dict_paths, dedup_crashes, None, |_s, _o| {
                 libafl::schedulers::QueueScheduler::new()
             });
         }
@@ -221,18 +473,58 @@ unsafe fn single_core_run<H>(
                 crash_dir,
                 seed_count,
                 timeout,
+                cmplog,
+                dict_paths,
+                dedup_crashes,
+                None,
                 |state, observer| crate::schedulers::StdWeightedScheduler::new(
                     &mut state, &observer
                 )
             );
         }
+        #[cfg(feature = "tui")]
+        (SchedulerType::PowerQueue, true) => {
+            let mon = crate::monitors::tui_monitor();
+            run_engine!(
+                harness,
+                mon,
+                crash_dir,
+                seed_count,
+                timeout,
+                cmplog,
+                dict_paths,
+                dedup_crashes,
+                Some(power_schedule),
+                |state, observer| libafl::schedulers::powersched::PowerQueueScheduler::new(
+                    &mut state, &observer, libafl_power_schedule(power_schedule)
+                )
+            );
+        }
+        #[cfg(feature = "tui")]
+        (SchedulerType::Minimizer, true) => {
+            let mon = crate::monitors::tui_monitor();
+            run_engine!(
+                harness,
+                mon,
+                crash_dir,
+                seed_count,
+                timeout,
+                cmplog,
+                dict_paths,
+                dedup_crashes,
+                None,
+                |_state, observer| libafl::schedulers::IndexesLenTimeMinimizerScheduler::new(
+                    &observer, libafl::schedulers::QueueScheduler::new()
+                )
+            );
+        }
         #[cfg(not(feature = "tui"))]
         (_, true) => {
             eprintln!("TUI requested but `tui` feature not compiled. Falling back to console.");
             let mon = crate::monitors::simple_monitor();
             match scheduler_type {
                 SchedulerType::Queue => {
-                    run_engine!(harness, mon, crash_dir, seed_count, timeout, |_s, _o| {
+                    run_engine!(harness, mon, crash_dir, seed_count, timeout, cmplog, dict_paths, dedup_crashes, None, |_s, _o| {
                         libafl::schedulers::QueueScheduler::new()
                     });
                 }
@@ -243,20 +535,68 @@ unsafe fn single_core_run<H>(
                         crash_dir,
                         seed_count,
                         timeout,
+                        cmplog,
+                        dict_paths,
+                        dedup_crashes,
+                        None,
                         |state, observer| crate::schedulers::StdWeightedScheduler::new(
                             &mut state, &observer
                         )
                     );
                 }
+                SchedulerType::PowerQueue => {
+                    run_engine!(
+                        harness,
+                        mon,
+                        crash_dir,
+                        seed_count,
+                        timeout,
+                        cmplog,
+                        dict_paths,
+                        dedup_crashes,
+                        Some(power_schedule),
+                        |state, observer| libafl::schedulers::powersched::PowerQueueScheduler::new(
+                            &mut state, &observer, libafl_power_schedule(power_schedule)
+                        )
+                    );
+                }
+                SchedulerType::Minimizer => {
+                    run_engine!(
+                        harness,
+                        mon,
+                        crash_dir,
+                        seed_count,
+                        timeout,
+                        cmplog,
+                        dict_paths,
+                        dedup_crashes,
+                        None,
+                        |_state, observer| libafl::schedulers::IndexesLenTimeMinimizerScheduler::new(
+                            &observer, libafl::schedulers::QueueScheduler::new()
+                        )
+                    );
+                }
             }
         }
     }
 }
 
+/// Maps our C-ABI `PowerSchedule` onto LibAFL's own `powersched::PowerSchedule`.
+fn libafl_power_schedule(schedule: PowerSchedule) -> libafl::schedulers::powersched::PowerSchedule {
+    match schedule {
+        PowerSchedule::Explore => libafl::schedulers::powersched::PowerSchedule::Explore,
+        PowerSchedule::Fast => libafl::schedulers::powersched::PowerSchedule::Fast,
+        PowerSchedule::Coe => libafl::schedulers::powersched::PowerSchedule::Coe,
+        PowerSchedule::Lin => libafl::schedulers::powersched::PowerSchedule::Lin,
+        PowerSchedule::Quad => libafl::schedulers::powersched::PowerSchedule::Quad,
+        PowerSchedule::Exploit => libafl::schedulers::powersched::PowerSchedule::Exploit,
+    }
+}
+
 /// Internal macro that stamps out the full fuzzer body, parameterized by a
 /// scheduler-construction expression that receives `|state, observer|`.
 macro_rules! run_engine {
-    ($harness:expr, $monitor:expr, $crash_dir:expr, $seed_count:expr, $timeout:expr,
+    ($harness:expr, $monitor:expr, $crash_dir:expr, $seed_count:expr, $timeout:expr, $cmplog:expr, $dict_paths:expr, $dedup_crashes:expr, $power_schedule:expr,
      |$state:ident, $observer:ident| $make_scheduler:expr) => {{
         use core::num::NonZero;
         use std::path::PathBuf;
@@ -267,12 +607,34 @@ macro_rules! run_engine {
             feedbacks::{CrashFeedback, EagerOrFeedback, MaxMapFeedback, TimeFeedback, TimeoutFeedback},
             fuzzer::{Fuzzer, StdFuzzer},
             generators::RandBytesGenerator,
-            mutators::{havoc_mutations::havoc_mutations, scheduled::HavocScheduledMutator},
-            observers::{StdMapObserver, TimeObserver},
+            mutators::{
+                havoc_mutations::havoc_mutations, scheduled::HavocScheduledMutator,
+                tokens_mutations, Tokens,
+            },
+            observers::{HitcountsMapObserver, StdMapObserver, TimeObserver},
             stages::mutational::StdMutationalStage,
-            state::{HasCorpus, StdState},
+            state::{HasCorpus, HasMetadata, StdState},
         };
-        use libafl_bolts::{current_nanos, rands::StdRand, tuples::tuple_list};
+        #[cfg(feature = "cmplog")]
+        use libafl::{
+            executors::inprocess::InProcessExecutor,
+            mutators::I2SRandReplace,
+            stages::tracing::TracingStage,
+        };
+        // `CmpLogObserver` lives in `libafl_targets`, not `libafl`: it reads the
+        // AFL++-style cmplog map that `libafl_targets`'s own
+        // `__sanitizer_cov_trace_cmp*`/`_const_*` hooks populate when the target
+        // executes a traced comparison. PeelFuzz takes a direct dependency on
+        // `libafl_targets` (`cmplog` feature) so those hooks get linked into the
+        // final binary; the harness itself must still be built with cmplog-aware
+        // instrumentation (e.g. `cargo-fuzz`/`afl-clang-fast`'s cmplog pass) for the
+        // map to ever be populated — plain pc-guard coverage isn't enough.
+        #[cfg(feature = "cmplog")]
+        use libafl_targets::cmplog::CmpLogObserver;
+        use libafl::feedbacks::{EagerAndFeedback, NewHashFeedback};
+        use libafl::observers::{BacktraceObserver, HarnessType as BacktraceHarnessType};
+        use libafl::stages::{calibrate::CalibrationStage, power::StdPowerMutationalStage};
+        use libafl_bolts::{current_nanos, rands::StdRand, tuples::{tuple_list, Merge}};
 
         use crate::sanitizer_coverage::{MAP_SIZE, SIGNALS_PTR};
 
@@ -281,19 +643,142 @@ macro_rules! run_engine {
                 crate::sanitizer_coverage::init_coverage();
             }
 
-            let $observer = StdMapObserver::from_mut_ptr("signals", SIGNALS_PTR, MAP_SIZE);
+            // `SIGNALS` holds raw per-edge hit counts (see `mark_coverage`);
+            // classify them into AFL's logarithmic buckets before feeding
+            // `MaxMapFeedback`, so repeat hits on a hot edge don't drown out
+            // newly discovered edges.
+            let $observer = HitcountsMapObserver::new(StdMapObserver::from_mut_ptr(
+                "signals",
+                SIGNALS_PTR,
+                MAP_SIZE,
+            ));
             let time_observer = TimeObserver::new("time");
 
+            // A second handle onto the same map feedback for `CalibrationStage`,
+            // built only when `$power_schedule` is set so the Queue/Weighted/
+            // Minimizer schedulers don't carry around a feedback handle they
+            // never read.
+            let map_feedback = $power_schedule.is_some().then(|| MaxMapFeedback::new(&$observer));
             let mut feedback = EagerOrFeedback::new(
                 MaxMapFeedback::new(&$observer),
                 TimeFeedback::new(&time_observer),
             );
             // Treat both crashes and timeouts as objectives
-            let mut objective = EagerOrFeedback::new(
+            let crash_or_timeout = EagerOrFeedback::new(
                 CrashFeedback::new(),
                 TimeoutFeedback::new(),
             );
 
+            if $dedup_crashes {
+                // Only save a crash once its backtrace hash hasn't been seen
+                // before, instead of once per crashing input.
+                let backtrace_observer =
+                    BacktraceObserver::owned("backtrace", BacktraceHarnessType::InProcess);
+                let mut objective =
+                    EagerAndFeedback::new(crash_or_timeout, NewHashFeedback::new(&backtrace_observer));
+
+                let mut $state = StdState::new(
+                    StdRand::with_seed(current_nanos()),
+                    InMemoryCorpus::new(),
+                    OnDiskCorpus::new(PathBuf::from($crash_dir)).unwrap(),
+                    &mut feedback,
+                    &mut objective,
+                )
+                .unwrap();
+
+                if let Ok(dict_tokens) = crate::dictionary::parse_dict_files($dict_paths) {
+                    if !dict_tokens.is_empty() {
+                        $state.add_metadata(Tokens::new().add_tokens(dict_tokens.iter().cloned()));
+                    }
+                }
+
+                let mut mgr = SimpleEventManager::new($monitor);
+                let scheduler = $make_scheduler;
+                let mut fuzzer = StdFuzzer::new(scheduler, feedback, objective);
+
+                let mut executor = libafl::executors::inprocess::InProcessExecutor::with_timeout(
+                    &mut $harness,
+                    tuple_list!($observer, time_observer, backtrace_observer),
+                    &mut fuzzer,
+                    &mut $state,
+                    &mut mgr,
+                    $timeout,
+                )
+                .unwrap();
+
+                if $state.corpus().count() == 0 {
+                    let seed_sizes: [usize; 5] = [4, 16, 32, 64, 128];
+                    let seeds_per_size = $seed_count / seed_sizes.len();
+                    let remainder = $seed_count % seed_sizes.len();
+
+                    for (i, &size) in seed_sizes.iter().enumerate() {
+                        let count = seeds_per_size + if i < remainder { 1 } else { 0 };
+                        if count > 0 {
+                            let mut generator = RandBytesGenerator::new(NonZero::new(size).unwrap());
+                            $state
+                                .generate_initial_inputs(
+                                    &mut fuzzer,
+                                    &mut executor,
+                                    &mut generator,
+                                    &mut mgr,
+                                    count,
+                                )
+                                .unwrap();
+                        }
+                    }
+                }
+
+                let mutator = HavocScheduledMutator::new(havoc_mutations().merge(tokens_mutations()));
+
+                #[cfg(feature = "cmplog")]
+                {
+                    if $cmplog {
+                        let cmplog_observer = CmpLogObserver::new("cmplog", true);
+                        let mut cmplog_executor = InProcessExecutor::new(
+                            &mut $harness,
+                            tuple_list!(cmplog_observer),
+                            &mut fuzzer,
+                            &mut $state,
+                            &mut mgr,
+                        )
+                        .unwrap();
+                        let tracing_stage = TracingStage::new(&mut cmplog_executor);
+
+                        let i2s_mutator = HavocScheduledMutator::new(tuple_list!(I2SRandReplace::new()));
+                        let mut stages = tuple_list!(
+                            tracing_stage,
+                            StdMutationalStage::new(i2s_mutator),
+                            StdMutationalStage::new(mutator)
+                        );
+
+                        fuzzer
+                            .fuzz_loop(&mut stages, &mut executor, &mut $state, &mut mgr)
+                            .unwrap();
+                        return;
+                    }
+                }
+
+                if $power_schedule.is_some() {
+                    let calibration = CalibrationStage::new(map_feedback.as_ref().unwrap());
+                    let power_stage = StdPowerMutationalStage::new(mutator);
+                    let mut stages = tuple_list!(calibration, power_stage);
+
+                    fuzzer
+                        .fuzz_loop(&mut stages, &mut executor, &mut $state, &mut mgr)
+                        .unwrap();
+                    return;
+                }
+
+                let mut stages = tuple_list!(StdMutationalStage::new(mutator));
+
+                fuzzer
+                    .fuzz_loop(&mut stages, &mut executor, &mut $state, &mut mgr)
+                    .unwrap();
+                return;
+            }
+
+            let mut objective = crash_or_timeout;
+
             let mut $state = StdState::new(
                 StdRand::with_seed(current_nanos()),
                 InMemoryCorpus::new(),
@@ -303,6 +788,12 @@ macro_rules! run_engine {
             )
             .unwrap();
 
+            if let Ok(dict_tokens) = crate::dictionary::parse_dict_files($dict_paths) {
+                if !dict_tokens.is_empty() {
+                    $state.add_metadata(Tokens::new().add_tokens(dict_tokens.iter().cloned()));
+                }
+            }
+
             let mut mgr = SimpleEventManager::new($monitor);
             let scheduler = $make_scheduler;
             let mut fuzzer = StdFuzzer::new(scheduler, feedback, objective);
@@ -340,7 +831,52 @@ macro_rules! run_engine {
                 }
             }
 
-            let mutator = HavocScheduledMutator::new(havoc_mutations());
+            let mutator = HavocScheduledMutator::new(havoc_mutations().merge(tokens_mutations()));
+
+            #[cfg(feature = "cmplog")]
+            {
+                if $cmplog {
+                    let cmplog_observer = CmpLogObserver::new("cmplog", true);
+                    let mut cmplog_executor = InProcessExecutor::new(
+                        &mut $harness,
+                        tuple_list!(cmplog_observer),
+                        &mut fuzzer,
+                        &mut $state,
+                        &mut mgr,
+                    )
+                    .unwrap();
+                    let tracing_stage = TracingStage::new(&mut cmplog_executor);
+
+                    // I2S runs first so magic-byte/checksum comparisons get
+                    // solved directly before havoc mutates them away.
+                    let i2s_mutator = HavocScheduledMutator::new(tuple_list!(I2SRandReplace::new()));
+                    let mut stages = tuple_list!(
+                        tracing_stage,
+                        StdMutationalStage::new(i2s_mutator),
+                        StdMutationalStage::new(mutator)
+                    );
+
+                    fuzzer
+                        .fuzz_loop(&mut stages, &mut executor, &mut $state, &mut mgr)
+                        .unwrap();
+                    return;
+                }
+            }
+
+            if $power_schedule.is_some() {
+                // Calibrate each new corpus entry's exec time and edge count,
+                // then let the power scheduler's energy assignment drive how
+                // many times `PowerMutationalStage` mutates it per round.
+                let calibration = CalibrationStage::new(map_feedback.as_ref().unwrap());
+                let power_stage = StdPowerMutationalStage::new(mutator);
+                let mut stages = tuple_list!(calibration, power_stage);
+
+                fuzzer
+                    .fuzz_loop(&mut stages, &mut executor, &mut $state, &mut mgr)
+                    .unwrap();
+                return;
+            }
+
             let mut stages = tuple_list!(StdMutationalStage::new(mutator));
 
             fuzzer
@@ -355,28 +891,42 @@ use run_engine;
 /// Multicore macro using LibAFL Launcher with fork-based parallelism.
 #[cfg(feature = "fork")]
 macro_rules! run_engine_multicore {
-    ($harness:expr, $monitor:expr, $crash_dir:expr, $seed_count:expr, $timeout:expr, $core_count:expr,
+    ($harness:expr, $monitor:expr, $crash_dir:expr, $seed_count:expr, $timeout:expr, $core_count:expr, $cmplog:expr, $dict_paths:expr, $dedup_crashes:expr, $power_schedule:expr,
      |$state:ident, $observer:ident| $make_scheduler:expr) => {{
         use core::num::NonZero;
         use std::path::PathBuf;
 
+        #[cfg(feature = "cmplog")]
+        if $cmplog {
+            eprintln!(
+                "cmplog requested but the multicore cmplog path is not yet wired up; \
+                 falling back to plain havoc mutation."
+            );
+        }
+
         use libafl::{
             corpus::{Corpus, InMemoryCorpus, OnDiskCorpus},
             events::{EventConfig, launcher::Launcher},
-            feedbacks::{CrashFeedback, EagerOrFeedback, MaxMapFeedback, TimeFeedback, TimeoutFeedback},
+            feedbacks::{
+                CrashFeedback, EagerAndFeedback, EagerOrFeedback, MaxMapFeedback, NewHashFeedback,
+                TimeFeedback, TimeoutFeedback,
+            },
             fuzzer::{Fuzzer, StdFuzzer},
             generators::RandBytesGenerator,
-            mutators::{havoc_mutations::havoc_mutations, scheduled::HavocScheduledMutator},
-            observers::{StdMapObserver, TimeObserver},
-            stages::mutational::StdMutationalStage,
-            state::{HasCorpus, StdState},
+            mutators::{
+                havoc_mutations::havoc_mutations, scheduled::HavocScheduledMutator,
+                tokens_mutations, Tokens,
+            },
+            observers::{BacktraceObserver, HarnessType as BacktraceHarnessType, HitcountsMapObserver, StdMapObserver, TimeObserver},
+            stages::{calibrate::CalibrationStage, mutational::StdMutationalStage, power::StdPowerMutationalStage},
+            state::{HasCorpus, HasMetadata, StdState},
         };
         use libafl_bolts::{
             current_nanos,
             core_affinity::Cores,
             rands::StdRand,
             shmem::{ShMemProvider, StdShMemProvider},
-            tuples::tuple_list,
+            tuples::{tuple_list, Merge},
         };
 
         use crate::sanitizer_coverage::{MAP_SIZE, SIGNALS_PTR};
@@ -388,6 +938,7 @@ macro_rules! run_engine_multicore {
         let crash_dir = $crash_dir.clone();
         let seed_count = $seed_count;
         let timeout = $timeout;
+        let dict_tokens = crate::dictionary::parse_dict_files($dict_paths).unwrap_or_default();
 
         let mut launcher = Launcher::builder()
             .shmem_provider(shmem_provider)
@@ -400,19 +951,114 @@ macro_rules! run_engine_multicore {
                         crate::sanitizer_coverage::init_coverage();
                     }
 
-                    let $observer = StdMapObserver::from_mut_ptr("signals", SIGNALS_PTR, MAP_SIZE);
+                    // See the single-core `run_engine!` for why this is
+                    // wrapped in `HitcountsMapObserver`.
+                    let $observer = HitcountsMapObserver::new(StdMapObserver::from_mut_ptr(
+                        "signals",
+                        SIGNALS_PTR,
+                        MAP_SIZE,
+                    ));
                     let time_observer = TimeObserver::new("time");
 
+                    // Only built when a power schedule is actually selected, so the
+                    // Queue/Weighted/Minimizer schedulers don't carry around a feedback
+                    // handle they never read.
+                    let map_feedback = $power_schedule.is_some().then(|| MaxMapFeedback::new(&$observer));
                     let mut feedback = EagerOrFeedback::new(
                         MaxMapFeedback::new(&$observer),
                         TimeFeedback::new(&time_observer),
                     );
                     // Treat both crashes and timeouts as objectives
-                    let mut objective = EagerOrFeedback::new(
+                    let crash_or_timeout = EagerOrFeedback::new(
                         CrashFeedback::new(),
                         TimeoutFeedback::new(),
                     );
 
+                    let backtrace_observer = if $dedup_crashes {
+                        Some(BacktraceObserver::owned("backtrace", BacktraceHarnessType::InProcess))
+                    } else {
+                        None
+                    };
+
+                    if let Some(backtrace_observer) = backtrace_observer {
+                        // Only save a crash once its backtrace hash hasn't
+                        // been seen before, instead of once per crashing input.
+                        let mut objective = EagerAndFeedback::new(
+                            crash_or_timeout,
+                            NewHashFeedback::new(&backtrace_observer),
+                        );
+
+                        let mut $state = StdState::new(
+                            StdRand::with_seed(current_nanos()),
+                            InMemoryCorpus::new(),
+                            OnDiskCorpus::new(PathBuf::from(crash_dir.clone())).unwrap(),
+                            &mut feedback,
+                            &mut objective,
+                        )
+                        .unwrap();
+
+                        if !dict_tokens.is_empty() {
+                            $state.add_metadata(Tokens::new().add_tokens(dict_tokens.iter().cloned()));
+                        }
+
+                        let scheduler = $make_scheduler;
+                        let mut fuzzer = StdFuzzer::new(scheduler, feedback, objective);
+
+                        let mut executor = libafl::executors::inprocess::InProcessExecutor::with_timeout(
+                            &mut $harness,
+                            tuple_list!($observer, time_observer, backtrace_observer),
+                            &mut fuzzer,
+                            &mut $state,
+                            &mut mgr,
+                            timeout,
+                        )
+                        .unwrap();
+
+                        if $state.corpus().count() == 0 {
+                            let seed_sizes: [usize; 5] = [4, 16, 32, 64, 128];
+                            let seeds_per_size = seed_count / seed_sizes.len();
+                            let remainder = seed_count % seed_sizes.len();
+
+                            for (i, &size) in seed_sizes.iter().enumerate() {
+                                let count = seeds_per_size + if i < remainder { 1 } else { 0 };
+                                if count > 0 {
+                                    let mut generator = RandBytesGenerator::new(NonZero::new(size).unwrap());
+                                    $state
+                                        .generate_initial_inputs(
+                                            &mut fuzzer,
+                                            &mut executor,
+                                            &mut generator,
+                                            &mut mgr,
+                                            count,
+                                        )
+                                        .unwrap();
+                                }
+                            }
+                        }
+
+                        let mutator = HavocScheduledMutator::new(havoc_mutations().merge(tokens_mutations()));
+
+                        if $power_schedule.is_some() {
+                            let calibration = CalibrationStage::new(map_feedback.as_ref().unwrap());
+                            let power_stage = StdPowerMutationalStage::new(mutator);
+                            let mut stages = tuple_list!(calibration, power_stage);
+
+                            fuzzer
+                                .fuzz_loop(&mut stages, &mut executor, &mut $state, &mut mgr)
+                                .unwrap();
+                        } else {
+                            let mut stages = tuple_list!(StdMutationalStage::new(mutator));
+
+                            fuzzer
+                                .fuzz_loop(&mut stages, &mut executor, &mut $state, &mut mgr)
+                                .unwrap();
+                        }
+
+                        return Ok(());
+                    }
+
+                    let mut objective = crash_or_timeout;
+
                     let mut $state = StdState::new(
                         StdRand::with_seed(current_nanos()),
                         InMemoryCorpus::new(),
@@ -422,6 +1068,10 @@ macro_rules! run_engine_multicore {
                     )
                     .unwrap();
 
+                    if !dict_tokens.is_empty() {
+                        $state.add_metadata(Tokens::new().add_tokens(dict_tokens.iter().cloned()));
+                    }
+
                     let scheduler = $make_scheduler;
                     let mut fuzzer = StdFuzzer::new(scheduler, feedback, objective);
 
@@ -457,12 +1107,23 @@ macro_rules! run_engine_multicore {
                         }
                     }
 
-                    let mutator = HavocScheduledMutator::new(havoc_mutations());
-                    let mut stages = tuple_list!(StdMutationalStage::new(mutator));
+                    let mutator = HavocScheduledMutator::new(havoc_mutations().merge(tokens_mutations()));
 
-                    fuzzer
-                        .fuzz_loop(&mut stages, &mut executor, &mut $state, &mut mgr)
-                        .unwrap();
+                    if $power_schedule.is_some() {
+                        let calibration = CalibrationStage::new(map_feedback.as_ref().unwrap());
+                        let power_stage = StdPowerMutationalStage::new(mutator);
+                        let mut stages = tuple_list!(calibration, power_stage);
+
+                        fuzzer
+                            .fuzz_loop(&mut stages, &mut executor, &mut $state, &mut mgr)
+                            .unwrap();
+                    } else {
+                        let mut stages = tuple_list!(StdMutationalStage::new(mutator));
+
+                        fuzzer
+                            .fuzz_loop(&mut stages, &mut executor, &mut $state, &mut mgr)
+                            .unwrap();
+                    }
                 }
 
                 Ok(())
@@ -476,3 +1137,162 @@ macro_rules! run_engine_multicore {
 
 #[cfg(feature = "fork")]
 use run_engine_multicore;
+
+/// Drive an external instrumented binary over the AFL forkserver protocol,
+/// reusing the same scheduler/monitor plumbing as the in-process path.
+///
+/// Unlike [`PeelFuzzer`], this has no Rust harness closure: the target is a
+/// separate process started by the forkserver, and inputs are delivered via
+/// an `@@` argv placeholder or over stdin when `target_argv` has none.
+///
+/// # Safety
+/// `target_path` must point at a binary built with AFL/LibAFL coverage
+/// instrumentation (e.g. `afl-cc`) matching `shmem_size`.
+pub unsafe fn run_forkserver(
+    target_path: &str,
+    target_argv: Vec<String>,
+    scheduler_type: SchedulerType,
+    timeout: Duration,
+    crash_dir: String,
+    seed_count: usize,
+    shmem_size: usize,
+) {
+    let mon = crate::monitors::simple_monitor();
+    match scheduler_type {
+        SchedulerType::Queue => {
+            run_forkserver_engine!(target_path, target_argv, mon, crash_dir, seed_count, timeout, shmem_size, |_s, _o| {
+                libafl::schedulers::QueueScheduler::new()
+            });
+        }
+        SchedulerType::Weighted => {
+            run_forkserver_engine!(
+                target_path, target_argv, mon, crash_dir, seed_count, timeout, shmem_size,
+                |state, observer| crate::schedulers::StdWeightedScheduler::new(&mut state, &observer)
+            );
+        }
+        SchedulerType::PowerQueue => {
+            eprintln!(
+                "PowerQueue scheduling is not supported for forkserver targets yet; \
+                 falling back to the plain queue scheduler."
+            );
+            run_forkserver_engine!(target_path, target_argv, mon, crash_dir, seed_count, timeout, shmem_size, |_s, _o| {
+                libafl::schedulers::QueueScheduler::new()
+            });
+        }
+        SchedulerType::Minimizer => {
+            run_forkserver_engine!(
+                target_path, target_argv, mon, crash_dir, seed_count, timeout, shmem_size,
+                |_state, observer| libafl::schedulers::IndexesLenTimeMinimizerScheduler::new(
+                    &observer, libafl::schedulers::QueueScheduler::new()
+                )
+            );
+        }
+    }
+}
+
+/// Internal macro mirroring `run_engine!`, but built around a
+/// `ForkserverExecutor` and shared-memory coverage map instead of the
+/// in-process `SIGNALS` array.
+macro_rules! run_forkserver_engine {
+    ($target_path:expr, $target_argv:expr, $monitor:expr, $crash_dir:expr, $seed_count:expr, $timeout:expr, $shmem_size:expr,
+     |$state:ident, $observer:ident| $make_scheduler:expr) => {{
+        use std::path::PathBuf;
+
+        use libafl::{
+            corpus::{Corpus, InMemoryCorpus, OnDiskCorpus},
+            events::SimpleEventManager,
+            executors::forkserver::{ForkserverExecutor, TimeoutForkserverExecutor},
+            feedbacks::{CrashFeedback, EagerOrFeedback, MaxMapFeedback, TimeFeedback, TimeoutFeedback},
+            fuzzer::{Fuzzer, StdFuzzer},
+            generators::RandBytesGenerator,
+            mutators::{havoc_mutations::havoc_mutations, scheduled::HavocScheduledMutator},
+            observers::{StdMapObserver, TimeObserver},
+            stages::mutational::StdMutationalStage,
+            state::{HasCorpus, StdState},
+        };
+        use libafl_bolts::{
+            current_nanos,
+            rands::StdRand,
+            shmem::{ShMemProvider, StdShMemProvider},
+            tuples::tuple_list,
+            AsSliceMut,
+        };
+
+        let mut shmem_provider = StdShMemProvider::new().unwrap();
+        let mut shmem = shmem_provider.new_shmem($shmem_size).unwrap();
+        // Export the segment id so the forked child (an AFL/afl-cc-instrumented
+        // binary) knows which shared-memory map to attach its coverage counters
+        // to; without this the child never learns about our map and writes
+        // nowhere we can see.
+        shmem.write_to_env("__AFL_SHM_ID").unwrap();
+        let shmem_buf = shmem.as_slice_mut();
+
+        // `shmem_size` is only known at runtime (it's a builder field / C-ABI
+        // config value), so the map has to be sized via `StdMapObserver`
+        // rather than `ConstMapObserver`, whose size is a const generic.
+        let $observer = unsafe {
+            StdMapObserver::from_mut_ptr("shmem_cov", shmem_buf.as_mut_ptr(), $shmem_size)
+        };
+        let time_observer = TimeObserver::new("time");
+
+        let mut feedback = EagerOrFeedback::new(
+            MaxMapFeedback::new(&$observer),
+            TimeFeedback::new(&time_observer),
+        );
+        // Treat both crashes and timeouts as objectives
+        let mut objective = EagerOrFeedback::new(CrashFeedback::new(), TimeoutFeedback::new());
+
+        let mut $state = StdState::new(
+            StdRand::with_seed(current_nanos()),
+            InMemoryCorpus::new(),
+            OnDiskCorpus::new(PathBuf::from($crash_dir)).unwrap(),
+            &mut feedback,
+            &mut objective,
+        )
+        .unwrap();
+
+        let mut mgr = SimpleEventManager::new($monitor);
+        let scheduler = $make_scheduler;
+        let mut fuzzer = StdFuzzer::new(scheduler, feedback, objective);
+
+        // An `@@` entry delivers the input via a file argument; with no
+        // such entry the forkserver feeds the input over stdin instead.
+        let use_stdin = !$target_argv.iter().any(|a| a == "@@");
+        let forkserver = ForkserverExecutor::builder()
+            .program($target_path)
+            .args(&$target_argv)
+            .debug_child(false)
+            .shmem_provider(&mut shmem_provider)
+            .use_stdin(use_stdin)
+            .build(tuple_list!($observer, time_observer))
+            .unwrap();
+        let mut executor = TimeoutForkserverExecutor::new(forkserver, $timeout).unwrap();
+
+        if $state.corpus().count() == 0 {
+            let seed_sizes: [usize; 5] = [4, 16, 32, 64, 128];
+            let seeds_per_size = $seed_count / seed_sizes.len();
+            let remainder = $seed_count % seed_sizes.len();
+
+            for (i, &size) in seed_sizes.iter().enumerate() {
+                let count = seeds_per_size + if i < remainder { 1 } else { 0 };
+                if count > 0 {
+                    let mut generator = core::num::NonZero::new(size)
+                        .map(RandBytesGenerator::new)
+                        .unwrap();
+                    $state
+                        .generate_initial_inputs(&mut fuzzer, &mut executor, &mut generator, &mut mgr, count)
+                        .unwrap();
+                }
+            }
+        }
+
+        let mutator = HavocScheduledMutator::new(havoc_mutations());
+        let mut stages = tuple_list!(StdMutationalStage::new(mutator));
+
+        fuzzer
+            .fuzz_loop(&mut stages, &mut executor, &mut $state, &mut mgr)
+            .unwrap();
+    }};
+}
+
+use run_forkserver_engine;
@@ -11,6 +11,8 @@ pub mod sanitizer_coverage;
 #[cfg(feature = "std")]
 pub mod config;
 #[cfg(feature = "std")]
+pub mod dictionary;
+#[cfg(feature = "std")]
 mod engine;
 #[cfg(feature = "std")]
 mod harness;
@@ -22,7 +24,7 @@ mod schedulers;
 pub mod targets;
 
 #[cfg(feature = "std")]
-pub use engine::PeelFuzzer;
+pub use engine::{ForkserverFuzzer, PeelFuzzer};
 
 #[cfg(feature = "std")]
 use std::time::Duration;
@@ -39,6 +41,7 @@ pub unsafe extern "C" fn peel_fuzz_run(config: *const PeelFuzzConfig) {
         let timeout = Duration::from_millis(cfg.timeout_ms_or_default());
         let crash_dir = cfg.crash_dir_or_default();
         let seed_count = cfg.seed_count_or_default();
+        let dict_path = cfg.dict_path_opt();
 
         match cfg.harness_type {
             HarnessType::ByteSize => {
@@ -51,6 +54,10 @@ pub unsafe extern "C" fn peel_fuzz_run(config: *const PeelFuzzConfig) {
                     &crash_dir,
                     seed_count,
                     cfg.use_tui,
+                    cfg.use_cmplog,
+                    dict_path.clone(),
+                    cfg.power_schedule,
+                    cfg.dedup_crashes,
                 );
             }
             HarnessType::String => {
@@ -63,8 +70,29 @@ pub unsafe extern "C" fn peel_fuzz_run(config: *const PeelFuzzConfig) {
                     &crash_dir,
                     seed_count,
                     cfg.use_tui,
+                    cfg.use_cmplog,
+                    dict_path.clone(),
+                    cfg.power_schedule,
+                    cfg.dedup_crashes,
                 );
             }
+            HarnessType::Forkserver => {
+                let Some(target_path) = cfg.target_path_opt() else {
+                    eprintln!("HarnessType::Forkserver requires a non-null target_path");
+                    return;
+                };
+                unsafe {
+                    engine::run_forkserver(
+                        &target_path,
+                        cfg.target_argv_vec(),
+                        cfg.scheduler_type,
+                        timeout,
+                        crash_dir,
+                        seed_count,
+                        cfg.shmem_size_or_default(),
+                    );
+                }
+            }
         }
     }
 }
@@ -77,12 +105,20 @@ unsafe fn build_and_run(
     crash_dir: &str,
     seed_count: usize,
     use_tui: bool,
+    use_cmplog: bool,
+    dict_path: Option<String>,
+    power_schedule: config::PowerSchedule,
+    dedup_crashes: bool,
 ) {
     let mut builder = PeelFuzzer::new(harness)
         .scheduler(scheduler_type)
         .timeout(timeout)
         .crash_dir(crash_dir)
-        .seed_count(seed_count);
+        .seed_count(seed_count)
+        .cmplog(use_cmplog)
+        .dict_path(dict_path.as_deref())
+        .power_schedule(power_schedule)
+        .dedup_crashes(dedup_crashes);
 
     if use_tui {
         builder = builder.use_tui();
@@ -105,7 +141,158 @@ pub unsafe extern "C" fn fuzz_byte_size(target_fn: targets::CTargetFn) {
             seed_count: 0,
             core_count: 0,
             use_tui: false,
+            use_cmplog: false,
+            dict_path: core::ptr::null(),
+            target_path: core::ptr::null(),
+            target_argv: core::ptr::null(),
+            shmem_size: 0,
+            power_schedule: config::PowerSchedule::Explore,
+            dedup_crashes: false,
         };
         peel_fuzz_run(&config);
     }
 }
+
+unsafe extern "C" {
+    /// The target function a libFuzzer harness defines. PeelFuzz links
+    /// against it directly, the same way libFuzzer itself does.
+    fn LLVMFuzzerTestOneInput(data: *const u8, size: usize) -> core::ffi::c_int;
+}
+
+// `LLVMFuzzerInitialize` is genuinely optional in libFuzzer — most harnesses
+// only define `LLVMFuzzerTestOneInput`. A hard `extern "C"` declaration would
+// fail to link against any of them, so calling it is opt-in behind this
+// feature; enable it only for harnesses that define their own
+// `LLVMFuzzerInitialize`.
+#[cfg(feature = "libfuzzer-initialize")]
+unsafe extern "C" {
+    fn LLVMFuzzerInitialize(
+        argc: *mut core::ffi::c_int,
+        argv: *mut *mut *mut core::ffi::c_char,
+    ) -> core::ffi::c_int;
+}
+
+/// Adapts `LLVMFuzzerTestOneInput`'s signature to `targets::CTargetFn`.
+unsafe extern "C" fn libfuzzer_target_fn(data: *const u8, size: usize) {
+    unsafe {
+        LLVMFuzzerTestOneInput(data, size);
+    }
+}
+
+/// libFuzzer-compatible `main`: resolves `LLVMFuzzerTestOneInput` as the
+/// target function and translates libFuzzer-style CLI flags into fuzzer
+/// settings passed to `build_and_run`. This lets existing libFuzzer harnesses link against
+/// PeelFuzz unchanged, since `LLVMFuzzerInitialize` is genuinely optional in
+/// libFuzzer and most harnesses don't define it. Harnesses that do define
+/// one should enable the `libfuzzer-initialize` feature so it gets called
+/// once at startup.
+///
+/// Recognized flags: `-timeout=<secs>`, `-dict=<path>`, `-runs=<n>`,
+/// `-jobs=<n>`. Any argument not starting with `-` is taken as a corpus
+/// directory. Only `-timeout=` and `-dict=` take effect: corpus directories
+/// are not loaded (seeds are generated randomly), `-runs=` does not stop the
+/// loop, and `-jobs=` does not enable multicore; each of these prints a
+/// warning instead.
+#[cfg(feature = "std")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn peel_fuzz_libfuzzer_main(
+    argc: core::ffi::c_int,
+    argv: *const *const core::ffi::c_char,
+) -> core::ffi::c_int {
+    unsafe {
+        #[cfg(feature = "libfuzzer-initialize")]
+        {
+            let mut mut_argc = argc;
+            let mut mut_argv = argv as *mut *mut core::ffi::c_char;
+            LLVMFuzzerInitialize(&mut mut_argc, &mut mut_argv);
+        }
+
+        let args = libfuzzer_args(argc, argv);
+        let opts = parse_libfuzzer_args(&args);
+
+        if !opts.corpus_dirs.is_empty() {
+            eprintln!(
+                "peel_fuzz_libfuzzer_main: loading an existing corpus directory is not \
+                 yet supported; {} positional path(s) ignored, seeding randomly instead",
+                opts.corpus_dirs.len()
+            );
+        }
+        if opts.runs.is_some() {
+            eprintln!("peel_fuzz_libfuzzer_main: -runs= is not yet enforced; fuzzing runs until stopped");
+        }
+        if opts.jobs.is_some() {
+            eprintln!("peel_fuzz_libfuzzer_main: -jobs= is not yet wired up; running single-core");
+        }
+
+        let h = harness::bytes_harness(libfuzzer_target_fn);
+        build_and_run(
+            h,
+            SchedulerType::Queue,
+            opts.timeout.unwrap_or(Duration::from_secs(1)),
+            &opts.crash_dir,
+            8,
+            false,
+            false,
+            opts.dict_path,
+            config::PowerSchedule::Explore,
+            false,
+        );
+
+        0
+    }
+}
+
+#[cfg(feature = "std")]
+struct LibFuzzerOpts {
+    timeout: Option<Duration>,
+    dict_path: Option<String>,
+    runs: Option<u64>,
+    jobs: Option<u32>,
+    crash_dir: String,
+    corpus_dirs: Vec<String>,
+}
+
+/// Collect a C `argv` into owned Rust strings, skipping `argv[0]`.
+#[cfg(feature = "std")]
+unsafe fn libfuzzer_args(argc: core::ffi::c_int, argv: *const *const core::ffi::c_char) -> Vec<String> {
+    unsafe {
+        (1..argc as isize)
+            .map(|i| {
+                core::ffi::CStr::from_ptr(*argv.offset(i))
+                    .to_string_lossy()
+                    .into_owned()
+            })
+            .collect()
+    }
+}
+
+/// Parse libFuzzer-style flags (`-name=value`), treating any argument that
+/// doesn't start with `-` as a positional corpus directory. Unrecognized
+/// flags are ignored.
+#[cfg(feature = "std")]
+fn parse_libfuzzer_args(args: &[String]) -> LibFuzzerOpts {
+    let mut opts = LibFuzzerOpts {
+        timeout: None,
+        dict_path: None,
+        runs: None,
+        jobs: None,
+        crash_dir: "./crashes".to_string(),
+        corpus_dirs: Vec::new(),
+    };
+
+    for arg in args {
+        if let Some(value) = arg.strip_prefix("-timeout=") {
+            opts.timeout = value.parse().ok().map(Duration::from_secs);
+        } else if let Some(value) = arg.strip_prefix("-dict=") {
+            opts.dict_path = Some(value.to_string());
+        } else if let Some(value) = arg.strip_prefix("-runs=") {
+            opts.runs = value.parse().ok();
+        } else if let Some(value) = arg.strip_prefix("-jobs=") {
+            opts.jobs = value.parse().ok();
+        } else if !arg.starts_with('-') {
+            opts.corpus_dirs.push(arg.clone());
+        }
+    }
+
+    opts
+}
@@ -12,12 +12,17 @@ pub unsafe fn init_coverage() {
     }
 }
 
-/// Mark a coverage hit at the given index.
+/// Mark a coverage hit at the given index, incrementing its hit count and
+/// saturating at 255 rather than writing a flat `1`. `HitcountsMapObserver`
+/// classifies these raw counts into AFL's logarithmic buckets downstream, so
+/// repeat hits on a hot edge stay distinguishable from a single hit.
 #[inline(always)]
 pub unsafe fn mark_coverage(idx: usize) {
     unsafe {
         if idx < MAP_SIZE {
-            write(SIGNALS_PTR.add(idx), 1);
+            let slot = SIGNALS_PTR.add(idx);
+            let count = core::ptr::read(slot);
+            write(slot, count.saturating_add(1));
         }
     }
 }